@@ -4,7 +4,55 @@ use std::{
     sync::Arc,
 };
 
-use crate::{BufferRef, ALIGNMENT};
+use crate::{
+    endian,
+    pod::{self, Pod, PodCastError},
+    BufferRef, ALIGNMENT,
+};
+
+/// Generates a pair of little/big-endian accessors that read an unaligned `$ty` out of
+///  `self.as_slice()` at a byte offset.
+///
+/// # Panics
+///
+/// The generated methods panic if `offset + size_of::<$ty>() > self.len()`.
+macro_rules! impl_get_endian {
+    ($(($get_le:ident, $get_be:ident, $ty:ty)),+ $(,)?) => {
+        $(
+            #[doc = concat!("Reads a little-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $get_le(&self, offset: usize) -> $ty {
+                endian::get_le(self.as_slice(), offset)
+            }
+
+            #[doc = concat!("Reads a big-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $get_be(&self, offset: usize) -> $ty {
+                endian::get_be(self.as_slice(), offset)
+            }
+        )+
+    };
+}
+
+/// Generates a pair of little/big-endian setters that write an unaligned `$ty` into
+///  `self.as_mut_slice()` at a byte offset.
+///
+/// # Panics
+///
+/// The generated methods panic if `offset + size_of::<$ty>() > self.len()`.
+macro_rules! impl_put_endian {
+    ($(($put_le:ident, $put_be:ident, $ty:ty)),+ $(,)?) => {
+        $(
+            #[doc = concat!("Writes a little-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $put_le(&mut self, offset: usize, value: $ty) {
+                endian::put_le(self.as_mut_slice(), offset, value)
+            }
+
+            #[doc = concat!("Writes a big-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $put_be(&mut self, offset: usize, value: $ty) {
+                endian::put_be(self.as_mut_slice(), offset, value)
+            }
+        )+
+    };
+}
 
 /// Buffer is a mutable byte container that is aligned to [crate::ALIGNMENT] bytes,
 /// and has a padding so the size of the underlying allocation is a multiple of [crate::ALIGNMENT].
@@ -15,6 +63,9 @@ pub struct Buffer {
     ptr: NonNull<u8>,
     layout: Layout,
     len: usize,
+    /// Size of the underlying allocation: a multiple of [crate::ALIGNMENT] bytes and at least
+    /// `len` (it can be larger after growing or shrinking). Zero if nothing has been allocated yet (`ptr` is dangling in that case).
+    capacity: usize,
 }
 
 impl Buffer {
@@ -34,6 +85,7 @@ impl Buffer {
                 ptr: std::ptr::NonNull::dangling(),
                 layout: Layout::from_size_align(64, 64).unwrap(),
                 len,
+                capacity: 0,
             };
         }
 
@@ -44,7 +96,12 @@ impl Buffer {
 
         let ptr = NonNull::new(ptr).unwrap();
 
-        Self { ptr, layout, len }
+        Self {
+            ptr,
+            layout,
+            len,
+            capacity: padded_len,
+        }
     }
 
     /// Get a pointer to the underlying memory.
@@ -71,6 +128,67 @@ impl Buffer {
         unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 
+    impl_get_endian!(
+        (get_u16_le, get_u16_be, u16),
+        (get_u32_le, get_u32_be, u32),
+        (get_u64_le, get_u64_be, u64),
+        (get_u128_le, get_u128_be, u128),
+        (get_i16_le, get_i16_be, i16),
+        (get_i32_le, get_i32_be, i32),
+        (get_i64_le, get_i64_be, i64),
+        (get_i128_le, get_i128_be, i128),
+    );
+
+    impl_put_endian!(
+        (put_u16_le, put_u16_be, u16),
+        (put_u32_le, put_u32_be, u32),
+        (put_u64_le, put_u64_be, u64),
+        (put_u128_le, put_u128_be, u128),
+        (put_i16_le, put_i16_be, i16),
+        (put_i32_le, put_i32_be, i32),
+        (put_i64_le, put_i64_be, i64),
+        (put_i128_le, put_i128_be, i128),
+    );
+
+    /// Reinterprets the underlying bytes as `&[T]` without copying.
+    ///
+    /// Returns `None` if `self.as_ptr()` is not aligned to `align_of::<T>()` or if
+    ///  `self.len()` is not a multiple of `size_of::<T>()`. An allocated `Buffer` is
+    ///  [ALIGNMENT]-byte aligned, so for `T` aligned to at most [ALIGNMENT] bytes only the
+    ///  length check can fail.
+    pub fn as_slice_of<T: Pod>(&self) -> Option<&[T]> {
+        pod::as_slice_of(self.as_ptr(), self.len)
+    }
+
+    /// Like [Self::as_slice_of] but returns a [PodCastError] describing why the cast failed.
+    pub fn try_as_slice_of<T: Pod>(&self) -> Result<&[T], PodCastError> {
+        pod::try_as_slice_of(self.as_ptr(), self.len)
+    }
+
+    /// Reinterprets the underlying bytes as `&mut [T]` without copying.
+    ///
+    /// See [Self::as_slice_of] for when this returns `None`.
+    pub fn as_mut_slice_of<T: Pod>(&mut self) -> Option<&mut [T]> {
+        pod::as_mut_slice_of(self.as_mut_ptr(), self.len)
+    }
+
+    /// Like [Self::as_mut_slice_of] but returns a [PodCastError] describing why the cast failed.
+    pub fn try_as_mut_slice_of<T: Pod>(&mut self) -> Result<&mut [T], PodCastError> {
+        pod::try_as_mut_slice_of(self.as_mut_ptr(), self.len)
+    }
+
+    /// Splits the bytes into an unaligned head, a middle slice of `T`-aligned lanes, and an
+    ///  unaligned tail. See [BufferRef::split_aligned] for intended usage, and
+    ///  [Self::split_aligned_mut] for the mutable version.
+    pub fn split_aligned<T: Pod>(&self) -> (&[u8], &[T], &[u8]) {
+        pod::split_aligned(self.as_ptr(), self.len)
+    }
+
+    /// Mutable version of [Self::split_aligned].
+    pub fn split_aligned_mut<T: Pod>(&mut self) -> (&mut [u8], &mut [T], &mut [u8]) {
+        pod::split_aligned_mut(self.as_mut_ptr(), self.len)
+    }
+
     /// Loads data from `src` to the underlying memory. Lenghth of `self` must be greater than or equal to the length of `src`
     ///
     /// This might be faster than the regular memcopy, especially for large copies. Because it bypasses the
@@ -87,6 +205,34 @@ impl Buffer {
         unsafe { crate::cold_load::cold_copy(src.as_ptr(), self.as_mut_ptr(), src.len()) }
     }
 
+    /// Copies data out of the underlying memory into `dst`. Length of `self` must be greater
+    ///  than or equal to the length of `dst`.
+    ///
+    /// This is the read-side counterpart of [Self::cold_load]: it bypasses the CPU cache when
+    ///  reading from `self` if possible, which is advantageous when streaming a buffer out
+    ///  that won't be read again soon, but not when `self` will also be used for other
+    ///  computation afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self.len() < dst.len()
+    pub fn cold_store(&self, dst: &mut [u8]) {
+        assert!(self.len >= dst.len());
+
+        unsafe { crate::cold_load::cold_copy_out(self.as_ptr(), dst.as_mut_ptr(), dst.len()) }
+    }
+
+    /// Fills the underlying memory with `byte`, including the [crate::ALIGNMENT]-byte
+    ///  padding. Since the padded allocation is always a multiple of 32 bytes, this can run a
+    ///  whole-lane AVX2 loop over it without a scalar cleanup loop.
+    pub fn fill(&mut self, byte: u8) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        unsafe { crate::simd::fill(self.as_mut_ptr(), self.capacity, byte) }
+    }
+
     /// Length of the buffer. Keep in mind that the underlying memory is padded to [crate::ALIGNMENT] bytes
     /// so might be bigger than the returned value.
     pub fn len(&self) -> usize {
@@ -98,6 +244,94 @@ impl Buffer {
         self.len() == 0
     }
 
+    /// Size of the underlying allocation. Always a multiple of [crate::ALIGNMENT] and at
+    ///  least [Self::len].
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be appended via
+    ///  [Self::extend_from_slice] or [Self::resize], without requiring another allocation.
+    ///
+    /// Grows the allocation by doubling (rounded up to [crate::ALIGNMENT] bytes) if needed,
+    ///  same as [Self::resize] and [Self::extend_from_slice].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() + additional` overflows `isize` when padded, or if memory can't
+    ///  be allocated.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).unwrap();
+
+        if required > self.capacity {
+            self.grow_to(required);
+        }
+    }
+
+    /// Resizes the buffer to `new_len`, growing the allocation if necessary. Newly exposed
+    ///  bytes (when growing) are zeroed, same as [Self::new].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` overflows `isize` when padded to [crate::ALIGNMENT] bytes, or if
+    ///  memory can't be allocated.
+    pub fn resize(&mut self, new_len: usize) {
+        if new_len > self.capacity {
+            self.grow_to(new_len);
+        } else if new_len > self.len {
+            // `grow_to` already zeroes the whole fresh allocation, but growing within the
+            //  existing capacity (e.g. after a prior shrink) exposes bytes that may still hold
+            //  data from before the shrink, so zero them here instead.
+            unsafe {
+                std::ptr::write_bytes(self.as_mut_ptr().add(self.len), 0, new_len - self.len);
+            }
+        }
+
+        self.len = new_len;
+    }
+
+    /// Appends the bytes of `src` to the end of the buffer, growing the allocation if
+    ///  necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.len() + src.len()` overflows `isize` when padded, or if memory can't
+    ///  be allocated.
+    pub fn extend_from_slice(&mut self, src: &[u8]) {
+        self.reserve(src.len());
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr().add(self.len), src.len());
+        }
+
+        self.len += src.len();
+    }
+
+    /// Grows the underlying allocation to hold at least `min_capacity` bytes, preserving the
+    ///  live `[0, self.len)` bytes and keeping the rest of the new allocation zeroed.
+    fn grow_to(&mut self, min_capacity: usize) {
+        let new_capacity = std::cmp::max(min_capacity, self.capacity.saturating_mul(2));
+        let padded_capacity = new_capacity.checked_next_multiple_of(ALIGNMENT).unwrap();
+        let new_layout = Layout::from_size_align(padded_capacity, ALIGNMENT).unwrap();
+
+        let new_ptr = unsafe { alloc_zeroed(new_layout) };
+        let new_ptr = NonNull::new(new_ptr).unwrap();
+
+        unsafe {
+            if self.len > 0 {
+                std::ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr(), self.len);
+            }
+
+            if self.capacity > 0 {
+                dealloc(self.ptr.as_ptr(), self.layout);
+            }
+        }
+
+        self.ptr = new_ptr;
+        self.layout = new_layout;
+        self.capacity = padded_capacity;
+    }
+
     /// Create a Buffer from given slice.
     pub fn from_slice(src: &[u8]) -> Self {
         // Has to be mut because we write to it with buf.ptr
@@ -130,8 +364,8 @@ impl Buffer {
 impl Drop for Buffer {
     fn drop(&mut self) {
         unsafe {
-            // Don't dealloc if we have 0 len, because we didn't alloc at the start.
-            if self.len > 0 {
+            // Don't dealloc if we never allocated anything.
+            if self.capacity > 0 {
                 dealloc(self.as_mut_ptr(), self.layout);
             }
         }
@@ -182,4 +416,136 @@ mod tests {
         let buf = Buffer::from_slice_cold(&src);
         assert_eq!(buf.as_slice(), src);
     }
+
+    #[test]
+    fn cold_store() {
+        let src = (0..244).collect::<Vec<u8>>();
+        let buf = Buffer::from_slice(&src);
+
+        let mut dst = vec![0; buf.len()];
+        buf.cold_store(&mut dst);
+        assert_eq!(dst, src);
+
+        let mut dst = vec![0; 3];
+        buf.cold_store(&mut dst);
+        assert_eq!(dst, &src[..3]);
+    }
+
+    #[test]
+    fn as_slice_of() {
+        let src = (0u8..16).collect::<Vec<u8>>();
+        let mut buf = Buffer::from_slice(&src);
+
+        let slice = buf.as_slice_of::<u32>().unwrap();
+        assert_eq!(slice.len(), 4);
+        assert_eq!(slice[0], u32::from_ne_bytes([0, 1, 2, 3]));
+
+        let slice = buf.as_mut_slice_of::<u32>().unwrap();
+        slice[0] = 0;
+        assert_eq!(buf.as_slice()[..4], [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn try_as_slice_of_invalid_length() {
+        let buf = Buffer::new(3);
+        assert_eq!(
+            buf.try_as_slice_of::<u32>().unwrap_err(),
+            crate::PodCastError::InvalidLength,
+        );
+    }
+
+    #[test]
+    fn split_aligned() {
+        // Buffer is 64-byte aligned, so the head is always empty, and a length that's a
+        //  multiple of `size_of::<u32>()` leaves an empty tail.
+        let mut buf = Buffer::from_slice(&(0u8..96).collect::<Vec<u8>>());
+
+        let (head, middle, tail) = buf.split_aligned::<u32>();
+        assert!(head.is_empty());
+        assert!(tail.is_empty());
+        assert_eq!(middle.len(), 24);
+
+        let (head, middle, tail) = buf.split_aligned_mut::<u32>();
+        assert!(head.is_empty());
+        assert!(tail.is_empty());
+        middle[0] = 0;
+        assert_eq!(buf.as_slice()[..4], [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn extend_from_slice_grows() {
+        let mut buf = Buffer::new(0);
+        assert_eq!(buf.capacity(), 0);
+
+        buf.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(buf.as_slice(), &[1, 2, 3]);
+        assert!(buf.capacity() >= 3);
+
+        buf.extend_from_slice(&(0..200).collect::<Vec<u8>>());
+        assert_eq!(buf.len(), 203);
+        assert_eq!(buf.as_slice()[..3], [1, 2, 3]);
+        assert_eq!(buf.as_slice()[3..], (0..200).collect::<Vec<u8>>()[..]);
+    }
+
+    #[test]
+    fn resize_zeroes_newly_exposed_bytes() {
+        let mut buf = Buffer::from_slice(&[1, 2, 3]);
+
+        buf.resize(10);
+        assert_eq!(buf.as_slice(), &[1, 2, 3, 0, 0, 0, 0, 0, 0, 0]);
+
+        buf.resize(1);
+        assert_eq!(buf.as_slice(), &[1]);
+    }
+
+    #[test]
+    fn resize_zeroes_bytes_exposed_by_regrowing_within_capacity() {
+        let mut buf = Buffer::new(10);
+        buf.as_mut_slice()[5] = 99;
+
+        buf.resize(3);
+        buf.resize(10);
+
+        assert_eq!(buf.as_slice(), &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn reserve_does_not_shrink() {
+        let mut buf = Buffer::from_slice(&[1, 2, 3]);
+        let capacity = buf.capacity();
+
+        buf.reserve(1);
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn endian_round_trip() {
+        let mut buf = Buffer::new(8);
+
+        buf.put_u32_le(0, 0x0102_0304);
+        assert_eq!(buf.as_slice()[..4], [0x04, 0x03, 0x02, 0x01]);
+        assert_eq!(buf.get_u32_le(0), 0x0102_0304);
+        assert_eq!(buf.get_u32_be(0), 0x0403_0201);
+
+        buf.put_i64_be(0, -1);
+        assert_eq!(buf.get_i64_be(0), -1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_endian_out_of_bounds() {
+        let buf = Buffer::new(2);
+        buf.get_u32_le(0);
+    }
+
+    #[test]
+    fn fill() {
+        let mut buf = Buffer::new(100);
+        buf.fill(7);
+        assert!(buf.as_slice().iter().all(|&b| b == 7));
+
+        let mut buf = Buffer::new(0);
+        buf.fill(7);
+        assert!(buf.is_empty());
+    }
 }
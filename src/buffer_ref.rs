@@ -1,6 +1,32 @@
 use std::sync::Arc;
 
-use crate::Buffer;
+use crate::{
+    endian,
+    pod::{self, Pod, PodCastError},
+    Buffer,
+};
+
+/// Generates a pair of little/big-endian accessors that read an unaligned `$ty` out of
+///  `self.as_slice()` at a byte offset.
+///
+/// # Panics
+///
+/// The generated methods panic if `offset + size_of::<$ty>() > self.len()`.
+macro_rules! impl_get_endian {
+    ($(($get_le:ident, $get_be:ident, $ty:ty)),+ $(,)?) => {
+        $(
+            #[doc = concat!("Reads a little-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $get_le(&self, offset: usize) -> $ty {
+                endian::get_le(self.as_slice(), offset)
+            }
+
+            #[doc = concat!("Reads a big-endian `", stringify!($ty), "` at `offset`.")]
+            pub fn $get_be(&self, offset: usize) -> $ty {
+                endian::get_be(self.as_slice(), offset)
+            }
+        )+
+    };
+}
 
 /// An immutable reference to a buffer. Can be used for shared zero copy views
 ///  into a single buffer.
@@ -68,6 +94,66 @@ impl BufferRef {
     pub fn as_slice(&self) -> &[u8] {
         unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len) }
     }
+
+    impl_get_endian!(
+        (get_u16_le, get_u16_be, u16),
+        (get_u32_le, get_u32_be, u32),
+        (get_u64_le, get_u64_be, u64),
+        (get_u128_le, get_u128_be, u128),
+        (get_i16_le, get_i16_be, i16),
+        (get_i32_le, get_i32_be, i32),
+        (get_i64_le, get_i64_be, i64),
+        (get_i128_le, get_i128_be, i128),
+    );
+
+    /// Reinterprets the underlying bytes as `&[T]` without copying.
+    ///
+    /// Returns `None` if `self.as_ptr()` is not aligned to `align_of::<T>()` or if
+    ///  `self.len()` is not a multiple of `size_of::<T>()`. An allocated [Buffer] is
+    ///  [crate::ALIGNMENT]-byte aligned, so for a `BufferRef` that starts at the beginning of
+    ///  one and a `T` aligned to at most [crate::ALIGNMENT] bytes only the length check can
+    ///  fail.
+    pub fn as_slice_of<T: Pod>(&self) -> Option<&[T]> {
+        pod::as_slice_of(self.as_ptr(), self.len)
+    }
+
+    /// Like [Self::as_slice_of] but returns a [PodCastError] describing why the cast failed.
+    pub fn try_as_slice_of<T: Pod>(&self) -> Result<&[T], PodCastError> {
+        pod::try_as_slice_of(self.as_ptr(), self.len)
+    }
+
+    /// Splits the bytes into an unaligned head, a middle slice of `T`-aligned lanes, and an
+    ///  unaligned tail, mirroring how [crate::cold_load::cold_copy] splits a region around the
+    ///  alignment boundary.
+    ///
+    /// This lets SIMD kernels run a scalar warm-up loop over the head, a vectorized loop over
+    ///  the middle (e.g. `__m256i` lanes when `T` is 32 bytes wide), and a scalar cleanup loop
+    ///  over the tail, without per-iteration bounds/alignment checks.
+    pub fn split_aligned<T: Pod>(&self) -> (&[u8], &[T], &[u8]) {
+        pod::split_aligned(self.as_ptr(), self.len)
+    }
+
+    /// Compares the bytes of `self` and `other` for equality.
+    ///
+    /// When there's room for a whole 32-byte vector past the last full chunk of both refs
+    ///  (up to the end of each underlying [Buffer]'s padded allocation), this masks off the
+    ///  out-of-range bytes of a single final vector comparison instead of running a separate
+    ///  scalar cleanup loop.
+    pub fn bytes_eq(&self, other: &BufferRef) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+
+        unsafe {
+            crate::simd::bytes_eq(
+                self.as_ptr(),
+                other.as_ptr(),
+                self.len,
+                self.inner.capacity() - self.start,
+                other.inner.capacity() - other.start,
+            )
+        }
+    }
 }
 
 #[cfg(test)]
@@ -88,4 +174,74 @@ mod tests {
             assert_eq!(buf_ref.as_slice()[1], 69);
         }
     }
+
+    #[test]
+    fn as_slice_of() {
+        let src = (0u8..16).collect::<Vec<u8>>();
+        let buf_ref = Buffer::from_slice(&src).into_ref();
+
+        let slice = buf_ref.as_slice_of::<u32>().unwrap();
+        assert_eq!(slice.len(), 4);
+
+        // A sub-slice that isn't aligned to 4 bytes fails the cast.
+        let unaligned = buf_ref.slice(1, 4);
+        assert_eq!(
+            unaligned.try_as_slice_of::<u32>().unwrap_err(),
+            PodCastError::Misaligned,
+        );
+    }
+
+    #[test]
+    fn split_aligned() {
+        let src = (0u8..16).collect::<Vec<u8>>();
+        let buf_ref = Buffer::from_slice(&src).into_ref();
+
+        // Offsetting by 1 byte shifts the middle slice's alignment boundary by 3 bytes.
+        let sliced = buf_ref.slice(1, 15);
+        let (head, middle, tail) = sliced.split_aligned::<u32>();
+        assert_eq!(head.len(), 3);
+        assert_eq!(middle.len(), 3);
+        assert_eq!(tail.len(), 0);
+    }
+
+    #[test]
+    fn split_aligned_len_shorter_than_alignment_gap() {
+        let src = (0u8..16).collect::<Vec<u8>>();
+        let buf_ref = Buffer::from_slice(&src).into_ref();
+
+        // Offset by 1 byte so the true alignment offset for `u64` (7) is bigger than `len` (3).
+        let sliced = buf_ref.slice(1, 3);
+        let (head, middle, tail) = sliced.split_aligned::<u64>();
+        assert_eq!(head.len(), 3);
+        assert_eq!(middle.len(), 0);
+        assert_eq!(tail.len(), 0);
+    }
+
+    #[test]
+    fn get_endian() {
+        let buf_ref = Buffer::from_slice(&[0x04, 0x03, 0x02, 0x01]).into_ref();
+
+        assert_eq!(buf_ref.get_u32_le(0), 0x0102_0304);
+        assert_eq!(buf_ref.get_u32_be(0), 0x0403_0201);
+    }
+
+    #[test]
+    fn bytes_eq() {
+        let src = (0u8..200).collect::<Vec<u8>>();
+        let a = Buffer::from_slice(&src).into_ref();
+        let b = Buffer::from_slice(&src).into_ref();
+
+        assert!(a.bytes_eq(&b));
+        assert!(!a.bytes_eq(&b.slice(0, 199)));
+
+        let mut other = src.clone();
+        other[30] = other[30].wrapping_add(1);
+        let c = Buffer::from_slice(&other).into_ref();
+        assert!(!a.bytes_eq(&c));
+
+        // Sub-slices starting inside their underlying buffer still have room up to its padded
+        //  end, so they take the masked final-vector path too.
+        assert!(a.slice(10, 50).bytes_eq(&b.slice(10, 50)));
+        assert!(!a.slice(10, 50).bytes_eq(&c.slice(10, 50)));
+    }
 }
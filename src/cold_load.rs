@@ -30,7 +30,7 @@ pub unsafe fn cold_copy(mut src: *const u8, mut dst: *mut u8, len: usize) {
         dst = dst.add(STEP);
     }
 
-    for _ in 0..len * STEP {
+    for _ in 0..len % STEP {
         *dst = *src;
 
         src = src.add(1);
@@ -48,3 +48,56 @@ pub unsafe fn cold_copy(mut src: *const u8, mut dst: *mut u8, len: usize) {
 pub unsafe fn cold_copy(src: *const u8, dst: *mut u8, len: usize) {
     std::ptr::copy_nonoverlapping(src, dst, len);
 }
+
+/// Copy `len` bytes from `src` to `dst`, bypassing the CPU cache when reading from `src`.
+///
+/// This is the read-side counterpart of [cold_copy], useful when streaming data out of a
+///  buffer that won't be read again soon, so it shouldn't be promoted into the cache.
+///
+/// # Safety
+///
+/// Length of both `src` and `dst` must be at least `len` bytes
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub unsafe fn cold_copy_out(mut src: *const u8, mut dst: *mut u8, len: usize) {
+    use core::arch::x86_64::{__m256i, _mm256_storeu_si256, _mm256_stream_load_si256, _mm_sfence};
+
+    let offset = src.align_offset(64);
+
+    for _ in 0..std::cmp::min(offset, len) {
+        *dst = *src;
+
+        dst = dst.add(1);
+        src = src.add(1);
+    }
+
+    let len = len.saturating_sub(offset);
+
+    const STEP: usize = 32;
+
+    for _ in 0..len / STEP {
+        _mm256_storeu_si256(dst as *mut __m256i, _mm256_stream_load_si256(src as *const __m256i));
+
+        src = src.add(STEP);
+        dst = dst.add(STEP);
+    }
+
+    _mm_sfence();
+
+    for _ in 0..len % STEP {
+        *dst = *src;
+
+        src = src.add(1);
+        dst = dst.add(1);
+    }
+}
+
+/// Copy `len` bytes from `src` to `dst`, bypassing the CPU cache when reading from `src`.
+///
+/// # Safety
+///
+/// Length of both `src` and `dst` must be at least `len` bytes
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+#[inline(always)]
+pub unsafe fn cold_copy_out(src: *const u8, dst: *mut u8, len: usize) {
+    std::ptr::copy_nonoverlapping(src, dst, len);
+}
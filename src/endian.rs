@@ -0,0 +1,95 @@
+/// A fixed-width integer that can be read from / written to a byte buffer in a chosen
+///  endianness, in the spirit of zerocopy's `byteorder` module.
+pub trait Endian: Sized + Copy {
+    /// Byte representation of `Self`, e.g. `[u8; 4]` for `u32`.
+    type Bytes: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+    fn to_le_bytes(self) -> Self::Bytes;
+    fn to_be_bytes(self) -> Self::Bytes;
+}
+
+macro_rules! impl_endian {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Endian for $ty {
+                type Bytes = [u8; std::mem::size_of::<$ty>()];
+
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_le_bytes(bytes)
+                }
+
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    <$ty>::from_be_bytes(bytes)
+                }
+
+                fn to_le_bytes(self) -> Self::Bytes {
+                    <$ty>::to_le_bytes(self)
+                }
+
+                fn to_be_bytes(self) -> Self::Bytes {
+                    <$ty>::to_be_bytes(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_endian!(u16, u32, u64, u128, i16, i32, i64, i128);
+
+/// Reads a little-endian `T` out of `bytes` at `offset`, doing an unaligned load.
+///
+/// # Panics
+///
+/// Panics if `offset + size_of::<T>() > bytes.len()`.
+pub(crate) fn get_le<T: Endian>(bytes: &[u8], offset: usize) -> T {
+    T::from_le_bytes(read_bytes(bytes, offset))
+}
+
+/// Reads a big-endian `T` out of `bytes` at `offset`, doing an unaligned load.
+///
+/// # Panics
+///
+/// Panics if `offset + size_of::<T>() > bytes.len()`.
+pub(crate) fn get_be<T: Endian>(bytes: &[u8], offset: usize) -> T {
+    T::from_be_bytes(read_bytes(bytes, offset))
+}
+
+/// Writes `value` to `bytes` at `offset` as little-endian, doing an unaligned store.
+///
+/// # Panics
+///
+/// Panics if `offset + size_of::<T>() > bytes.len()`.
+pub(crate) fn put_le<T: Endian>(bytes: &mut [u8], offset: usize, value: T) {
+    write_bytes(bytes, offset, value.to_le_bytes())
+}
+
+/// Writes `value` to `bytes` at `offset` as big-endian, doing an unaligned store.
+///
+/// # Panics
+///
+/// Panics if `offset + size_of::<T>() > bytes.len()`.
+pub(crate) fn put_be<T: Endian>(bytes: &mut [u8], offset: usize, value: T) {
+    write_bytes(bytes, offset, value.to_be_bytes())
+}
+
+fn read_bytes<B: Default + AsMut<[u8]>>(bytes: &[u8], offset: usize) -> B {
+    let mut out = B::default();
+    let out_ref = out.as_mut();
+
+    let end = offset.checked_add(out_ref.len()).unwrap();
+    assert!(end <= bytes.len());
+
+    out_ref.copy_from_slice(&bytes[offset..end]);
+    out
+}
+
+fn write_bytes<B: AsRef<[u8]>>(bytes: &mut [u8], offset: usize, value: B) {
+    let value_ref = value.as_ref();
+
+    let end = offset.checked_add(value_ref.len()).unwrap();
+    assert!(end <= bytes.len());
+
+    bytes[offset..end].copy_from_slice(value_ref);
+}
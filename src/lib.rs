@@ -3,12 +3,21 @@
 //!
 //! These interfaces are designed to be used in high performance applications where
 //!  cacheline alignment, SIMD instructions and zero-copy is important.
+mod arena;
 mod buffer;
 mod buffer_ref;
 mod cold_load;
+mod endian;
+mod pod;
+mod reader;
+mod simd;
 
+pub use arena::BufferArena;
 pub use buffer::Buffer;
 pub use buffer_ref::BufferRef;
+pub use endian::Endian;
+pub use pod::{Pod, PodCastError};
+pub use reader::BufferReader;
 
 /// Alignment of the Buffer memory
 pub const ALIGNMENT: usize = 64;
@@ -0,0 +1,142 @@
+/// Marker trait for types that are safe to reinterpret a byte slice as, in the spirit of
+///  zerocopy's `FromBytes`/`AsBytes`.
+///
+/// # Safety
+///
+/// Implementors must guarantee that any initialized sequence of bytes of the appropriate
+///  length is a valid value of `Self`, and that `Self` has no padding bytes and no interior
+///  pointers/references. This is true for plain old data like `u8`, `u32`, `f64` or
+///  `#[repr(C)]` structs made up of such types, but not for types with invalid bit patterns
+///  (e.g. `bool`, enums) or niches.
+pub unsafe trait Pod: Sized {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for u128 {}
+unsafe impl Pod for usize {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for i128 {}
+unsafe impl Pod for isize {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+
+/// Error returned by `try_as_slice_of`/`try_as_mut_slice_of` when the bytes can't be
+///  reinterpreted as a slice of `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PodCastError {
+    /// `as_ptr()` is not aligned to `align_of::<T>()`.
+    Misaligned,
+    /// `len` is not a multiple of `size_of::<T>()`.
+    InvalidLength,
+}
+
+impl std::fmt::Display for PodCastError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Misaligned => write!(f, "buffer is not aligned for target type"),
+            Self::InvalidLength => write!(f, "buffer length is not a multiple of target type size"),
+        }
+    }
+}
+
+impl std::error::Error for PodCastError {}
+
+/// Checks that `ptr`/`len` can be reinterpreted as a `&[T]`, returning the element count.
+fn check_cast<T: Pod>(ptr: *const u8, len: usize) -> Result<usize, PodCastError> {
+    if !(ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+        return Err(PodCastError::Misaligned);
+    }
+
+    if !len.is_multiple_of(std::mem::size_of::<T>()) {
+        return Err(PodCastError::InvalidLength);
+    }
+
+    Ok(len / std::mem::size_of::<T>())
+}
+
+/// Reinterprets `len` bytes starting at `ptr` as `&[T]`, or `None` if misaligned or if `len`
+///  isn't a multiple of `size_of::<T>()`.
+pub(crate) fn as_slice_of<'a, T: Pod>(ptr: *const u8, len: usize) -> Option<&'a [T]> {
+    try_as_slice_of(ptr, len).ok()
+}
+
+/// Like [as_slice_of] but returns a [PodCastError] describing why the cast failed.
+pub(crate) fn try_as_slice_of<'a, T: Pod>(ptr: *const u8, len: usize) -> Result<&'a [T], PodCastError> {
+    let n = check_cast::<T>(ptr, len)?;
+    Ok(unsafe { std::slice::from_raw_parts(ptr as *const T, n) })
+}
+
+/// Reinterprets `len` bytes starting at `ptr` as `&mut [T]`, or `None` if misaligned or if
+///  `len` isn't a multiple of `size_of::<T>()`.
+pub(crate) fn as_mut_slice_of<'a, T: Pod>(ptr: *mut u8, len: usize) -> Option<&'a mut [T]> {
+    try_as_mut_slice_of(ptr, len).ok()
+}
+
+/// Like [as_mut_slice_of] but returns a [PodCastError] describing why the cast failed.
+pub(crate) fn try_as_mut_slice_of<'a, T: Pod>(
+    ptr: *mut u8,
+    len: usize,
+) -> Result<&'a mut [T], PodCastError> {
+    let n = check_cast::<T>(ptr, len)?;
+    Ok(unsafe { std::slice::from_raw_parts_mut(ptr as *mut T, n) })
+}
+
+/// Computes the `(head_len, middle_elem_count, tail_len)` split of `len` bytes starting at
+///  `ptr` around the `align_of::<T>()` boundary.
+fn split_offsets<T: Pod>(ptr: *const u8, len: usize) -> (usize, usize, usize) {
+    let off = std::cmp::min(ptr.align_offset(std::mem::align_of::<T>()), len);
+    let n = (len - off) / std::mem::size_of::<T>();
+    let tail = (len - off) % std::mem::size_of::<T>();
+
+    (off, n, tail)
+}
+
+/// Splits `len` bytes starting at `ptr` into an unaligned head, a middle slice of `T`-aligned
+///  lanes, and an unaligned tail.
+pub(crate) fn split_aligned<'a, T: Pod>(ptr: *const u8, len: usize) -> (&'a [u8], &'a [T], &'a [u8]) {
+    let (off, n, tail) = split_offsets::<T>(ptr, len);
+
+    unsafe {
+        let head = std::slice::from_raw_parts(ptr, off);
+        // `off` may be clamped below the true alignment offset when it exceeds `len`, in
+        //  which case `ptr.add(off)` isn't actually aligned to `T`. `n` is always 0 in that
+        //  case, so use a dangling aligned pointer instead to uphold `from_raw_parts`'s
+        //  alignment precondition even for the empty slice.
+        let middle_ptr = if n == 0 {
+            std::ptr::NonNull::<T>::dangling().as_ptr() as *const T
+        } else {
+            ptr.add(off) as *const T
+        };
+        let middle = std::slice::from_raw_parts(middle_ptr, n);
+        let tail = std::slice::from_raw_parts(ptr.add(off + n * std::mem::size_of::<T>()), tail);
+
+        (head, middle, tail)
+    }
+}
+
+/// Mutable version of [split_aligned].
+pub(crate) fn split_aligned_mut<'a, T: Pod>(
+    ptr: *mut u8,
+    len: usize,
+) -> (&'a mut [u8], &'a mut [T], &'a mut [u8]) {
+    let (off, n, tail) = split_offsets::<T>(ptr, len);
+
+    unsafe {
+        let head = std::slice::from_raw_parts_mut(ptr, off);
+        // See the comment in [split_aligned] about the dangling pointer for the `n == 0` case.
+        let middle_ptr = if n == 0 {
+            std::ptr::NonNull::<T>::dangling().as_ptr()
+        } else {
+            ptr.add(off) as *mut T
+        };
+        let middle = std::slice::from_raw_parts_mut(middle_ptr, n);
+        let tail = std::slice::from_raw_parts_mut(ptr.add(off + n * std::mem::size_of::<T>()), tail);
+
+        (head, middle, tail)
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::{self, Read};
+
+use crate::BufferRef;
+
+/// A cursor over a [BufferRef] that tracks an advancing read position, so a shared zero-copy
+///  view can be consumed incrementally by I/O and parsing code.
+pub struct BufferReader {
+    inner: BufferRef,
+    pos: usize,
+}
+
+impl BufferReader {
+    /// Wraps `inner` in a reader starting at position 0.
+    pub fn new(inner: BufferRef) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Current read position, relative to the start of the wrapped [BufferRef].
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The bytes not yet consumed.
+    pub fn remaining_slice(&self) -> &[u8] {
+        &self.inner.as_slice()[self.pos..]
+    }
+}
+
+impl Read for BufferReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = std::cmp::min(buf.len(), self.remaining_slice().len());
+
+        buf[..n].copy_from_slice(&self.remaining_slice()[..n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl bytes::Buf for BufferReader {
+    fn remaining(&self) -> usize {
+        self.inner.len() - self.pos
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.remaining_slice()
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        assert!(cnt <= bytes::Buf::remaining(self));
+
+        self.pos += cnt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Buffer;
+
+    #[test]
+    fn reads_incrementally() {
+        let mut reader = BufferReader::new(Buffer::from_slice(&[1, 2, 3, 4, 5]).into_ref());
+
+        let mut buf = [0u8; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(reader.position(), 3);
+
+        let mut buf = [0u8; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], &[4, 5]);
+        assert_eq!(reader.position(), 5);
+
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn buf_impl_advances_and_reports_remaining() {
+        use bytes::Buf;
+
+        let mut reader = BufferReader::new(Buffer::from_slice(&[1, 2, 3, 4, 5]).into_ref());
+
+        assert_eq!(reader.remaining(), 5);
+        assert_eq!(reader.chunk(), &[1, 2, 3, 4, 5]);
+
+        reader.advance(2);
+        assert_eq!(reader.remaining(), 3);
+        assert_eq!(reader.chunk(), &[3, 4, 5]);
+        assert_eq!(reader.position(), 2);
+    }
+}
@@ -0,0 +1,88 @@
+/// Fills `len` bytes starting at `ptr` with `byte`.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `len` bytes. `len` must be a multiple of 32.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub unsafe fn fill(mut ptr: *mut u8, len: usize, byte: u8) {
+    use core::arch::x86_64::{__m256i, _mm256_set1_epi8, _mm256_store_si256};
+
+    debug_assert_eq!(len % 32, 0);
+
+    let v = _mm256_set1_epi8(byte as i8);
+
+    for _ in 0..len / 32 {
+        _mm256_store_si256(ptr as *mut __m256i, v);
+        ptr = ptr.add(32);
+    }
+}
+
+/// Fills `len` bytes starting at `ptr` with `byte`.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `len` bytes.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+pub unsafe fn fill(ptr: *mut u8, len: usize, byte: u8) {
+    std::ptr::write_bytes(ptr, byte, len);
+}
+
+/// Compares `len` bytes starting at `a` against `len` bytes starting at `b`.
+///
+/// `a_room`/`b_room` are how many bytes are safe to read starting at `a`/`b` respectively
+/// (which may be more than `len`, e.g. up to the padded end of the underlying allocation).
+/// When there's at least 32 bytes of room past the last full 32-byte chunk, the final partial
+/// chunk is compared with a single masked vector load instead of a byte-by-byte loop.
+///
+/// # Safety
+///
+/// `a`/`b` must be valid for reads of `a_room`/`b_room` bytes respectively, and
+/// `len <= a_room`, `len <= b_room`.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub unsafe fn bytes_eq(mut a: *const u8, mut b: *const u8, len: usize, a_room: usize, b_room: usize) -> bool {
+    use core::arch::x86_64::{__m256i, _mm256_cmpeq_epi8, _mm256_loadu_si256, _mm256_movemask_epi8};
+
+    const STEP: usize = 32;
+
+    let chunks = len / STEP;
+    let remainder = len % STEP;
+
+    for _ in 0..chunks {
+        let va = _mm256_loadu_si256(a as *const __m256i);
+        let vb = _mm256_loadu_si256(b as *const __m256i);
+
+        if _mm256_movemask_epi8(_mm256_cmpeq_epi8(va, vb)) != -1 {
+            return false;
+        }
+
+        a = a.add(STEP);
+        b = b.add(STEP);
+    }
+
+    if remainder == 0 {
+        return true;
+    }
+
+    let consumed = chunks * STEP;
+    if a_room - consumed >= STEP && b_room - consumed >= STEP {
+        let va = _mm256_loadu_si256(a as *const __m256i);
+        let vb = _mm256_loadu_si256(b as *const __m256i);
+
+        let mask = _mm256_movemask_epi8(_mm256_cmpeq_epi8(va, vb)) as u32;
+        let wanted = (1u32 << remainder) - 1;
+
+        mask & wanted == wanted
+    } else {
+        std::slice::from_raw_parts(a, remainder) == std::slice::from_raw_parts(b, remainder)
+    }
+}
+
+/// Compares `len` bytes starting at `a` against `len` bytes starting at `b`.
+///
+/// # Safety
+///
+/// `a`/`b` must be valid for reads of `len` bytes.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+pub unsafe fn bytes_eq(a: *const u8, b: *const u8, len: usize, _a_room: usize, _b_room: usize) -> bool {
+    std::slice::from_raw_parts(a, len) == std::slice::from_raw_parts(b, len)
+}